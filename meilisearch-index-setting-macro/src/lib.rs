@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use proc_macro2::Ident;
 use quote::quote;
-use structmeta::{Flag, NameValue, StructMeta};
+use structmeta::{Flag, NameArgs, NameValue, StructMeta};
 use syn::{parse_macro_input, spanned::Spanned, Attribute, LitStr};
 
 #[derive(Clone, StructMeta, Default)]
@@ -12,12 +12,64 @@ struct FieldAttrs {
     distinct: Flag,
     filterable: Flag,
     sortable: Flag,
+    rename: Option<NameValue<LitStr>>,
+    embedded: Flag,
+}
+
+#[derive(Clone, StructMeta)]
+struct EmbedderAttrs {
+    name: NameValue<LitStr>,
+    source: NameValue<LitStr>,
+    model: Option<NameValue<LitStr>>,
+    document_template: Option<NameValue<LitStr>>,
+}
+
+// `rest` catches every `word = [synonym, ...]` pair, so `#[index_config(synonyms(film = [...]))]`
+// reads as the single word -> synonym-list map the setting actually is, the same rest-parameter
+// idiom `SortFacetValuesByAttrs` uses below for its own attribute-name -> value map.
+#[derive(Clone, StructMeta, Default)]
+struct SynonymsAttrs {
+    rest: ::std::collections::HashMap<String, syn::ExprArray>,
+}
+
+#[derive(Clone, StructMeta, Default)]
+struct MinWordSizeForTyposAttrs {
+    one_typo: Option<NameValue<syn::Expr>>,
+    two_typos: Option<NameValue<syn::Expr>>,
+}
+
+#[derive(Clone, StructMeta)]
+struct TypoToleranceAttrs {
+    min_word_size_for_typos: Option<NameArgs<MinWordSizeForTyposAttrs>>,
+    disable_on_words: Option<NameValue<syn::ExprArray>>,
+    disable_on_attributes: Option<NameValue<syn::ExprArray>>,
+}
+
+#[derive(Clone, StructMeta, Default)]
+struct SortFacetValuesByAttrs {
+    rest: ::std::collections::HashMap<String, LitStr>,
+}
+
+#[derive(Clone, StructMeta, Default)]
+struct FacetingAttrs {
+    max_values_per_facet: Option<NameValue<syn::Expr>>,
+    sort_facet_values_by: Option<NameArgs<SortFacetValuesByAttrs>>,
 }
 
 #[derive(StructMeta)]
 struct StructAttrs {
     index_name: Option<NameValue<LitStr>>,
     max_total_hits: Option<NameValue<syn::Expr>>,
+    // Repeatable: stack multiple `#[index_config(embedder(...))]` attributes on the struct to
+    // declare more than one.
+    embedder: Option<NameArgs<EmbedderAttrs>>,
+    ranking_rules: Option<NameValue<syn::ExprArray>>,
+    stop_words: Option<NameValue<syn::ExprArray>>,
+    synonyms: Option<NameArgs<SynonymsAttrs>>,
+    typo_tolerance: Option<NameArgs<TypoToleranceAttrs>>,
+    search_cutoff_ms: Option<NameValue<syn::Expr>>,
+    proximity_precision: Option<NameValue<LitStr>>,
+    faceting: Option<NameArgs<FacetingAttrs>>,
 }
 
 fn is_valid_name(name: &str) -> bool {
@@ -26,6 +78,93 @@ fn is_valid_name(name: &str) -> bool {
         && !name.is_empty()
 }
 
+// Mirrors the subset of serde's `rename_all` casings that matters for JSON keys.
+// `lowercase`/`UPPERCASE` are handled separately in `rename_field` because they keep the
+// field's underscores, unlike convert_case's `Case::Lower`/`Case::Upper` which also turn
+// `_` into spaces.
+fn case_from_rename_all(value: &str) -> Option<Case> {
+    Some(match value {
+        "PascalCase" => Case::Pascal,
+        "camelCase" => Case::Camel,
+        "snake_case" => Case::Snake,
+        "SCREAMING_SNAKE_CASE" => Case::UpperSnake,
+        "kebab-case" => Case::Kebab,
+        "SCREAMING-KEBAB-CASE" => Case::Cobol,
+        _ => return None,
+    })
+}
+
+// serde's `rename_all = "lowercase"/"UPPERCASE"` only changes ASCII case and keeps the
+// identifier's underscores intact (`release_date` -> `release_date`/`RELEASE_DATE`), so they
+// can't be expressed through convert_case's word-splitting `Case::Lower`/`Case::Upper`.
+fn rename_field(field_ident: &str, rename_all: &str) -> Option<String> {
+    match rename_all {
+        "lowercase" => Some(field_ident.to_lowercase()),
+        "UPPERCASE" => Some(field_ident.to_uppercase()),
+        _ => case_from_rename_all(rename_all).map(|case| field_ident.to_case(case)),
+    }
+}
+
+fn string_list_from_expr_array(array: &syn::ExprArray) -> Vec<(String, proc_macro2::Span)> {
+    array
+        .elems
+        .iter()
+        .filter_map(|elem| match elem {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => Some((lit_str.value(), lit_str.span())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn find_serde_rename(attrs: &[Attribute], ident: &str) -> Option<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .find_map(|attr| {
+            let mut found = None;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(ident) {
+                    let value: LitStr = meta.value()?.parse()?;
+                    found = Some(value.value());
+                } else if meta.input.peek(syn::Token![=]) {
+                    // Skip other `key = value` options so parsing reaches later keys.
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::TokenTree>()?;
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
+// Resolves the JSON document key for a struct field, honoring (in order of priority)
+// an explicit `#[index_config(rename = "...")]`, serde's own `#[serde(rename = "...")]`,
+// the struct-level `#[serde(rename_all = "...")]` casing, and finally the raw field name.
+fn resolve_field_name(
+    field_ident: &str,
+    field_attrs: &[Attribute],
+    index_config_rename: Option<&str>,
+    struct_rename_all: Option<&str>,
+) -> String {
+    if let Some(rename) = index_config_rename {
+        return rename.to_string();
+    }
+
+    if let Some(rename) = find_serde_rename(field_attrs, "rename") {
+        return rename;
+    }
+
+    if let Some(name) = struct_rename_all.and_then(|rename_all| rename_field(field_ident, rename_all)) {
+        return name;
+    }
+
+    field_ident.to_string()
+}
+
 #[proc_macro_derive(IndexConfig, attributes(index_config))]
 pub fn generate_index_settings(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let syn::DeriveInput {
@@ -71,6 +210,17 @@ fn get_index_config_implementation(
 
     let mut max_total_hits = None;
 
+    let mut embedders = vec![];
+
+    let mut ranking_rules = None;
+    let mut stop_words = None;
+    let mut synonyms = None;
+    let mut typo_tolerance = None;
+
+    let mut search_cutoff_ms = None;
+    let mut proximity_precision = None;
+    let mut faceting = None;
+
     let struct_attrs =
         filter_attrs(&attrs).filter_map(|attr| attr.parse_args::<StructAttrs>().ok());
     for struct_attr in struct_attrs {
@@ -81,6 +231,38 @@ fn get_index_config_implementation(
         if let Some(max_total_hits_value) = struct_attr.max_total_hits {
             max_total_hits = Some(max_total_hits_value.value)
         }
+
+        if let Some(embedder) = struct_attr.embedder {
+            embedders.push(embedder.args);
+        }
+
+        if let Some(ranking_rules_value) = struct_attr.ranking_rules {
+            ranking_rules = Some(ranking_rules_value.value)
+        }
+
+        if let Some(stop_words_value) = struct_attr.stop_words {
+            stop_words = Some(stop_words_value.value)
+        }
+
+        if let Some(synonyms_value) = struct_attr.synonyms {
+            synonyms = Some(synonyms_value.args)
+        }
+
+        if let Some(typo_tolerance_value) = struct_attr.typo_tolerance {
+            typo_tolerance = Some(typo_tolerance_value.args)
+        }
+
+        if let Some(search_cutoff_ms_value) = struct_attr.search_cutoff_ms {
+            search_cutoff_ms = Some(search_cutoff_ms_value.value)
+        }
+
+        if let Some(proximity_precision_value) = struct_attr.proximity_precision {
+            proximity_precision = Some(proximity_precision_value)
+        }
+
+        if let Some(faceting_value) = struct_attr.faceting {
+            faceting = Some(faceting_value.args)
+        }
     }
 
     let (index_name, span) = index_name_override.unwrap_or_else(|| {
@@ -95,14 +277,29 @@ fn get_index_config_implementation(
             .to_compile_error();
     }
 
+    let struct_rename_all = find_serde_rename(&attrs, "rename_all");
+
     let mut primary_key_found = false;
     let mut distinct_found = false;
 
+    let mut embedded_fields = vec![];
+    let mut all_field_names = vec![];
+
     for field in fields {
         let attrs = filter_attrs(&field.attrs)
             .find_map(|attr| attr.parse_args::<FieldAttrs>().ok())
             .unwrap_or_default();
 
+        let field_ident = field.ident.clone().unwrap().to_string();
+        let index_config_rename = attrs.rename.as_ref().map(|rename| rename.value.value());
+        let field_name = resolve_field_name(
+            &field_ident,
+            &field.attrs,
+            index_config_rename.as_deref(),
+            struct_rename_all.as_deref(),
+        );
+        all_field_names.push(field_name.clone());
+
         // Check if the primary key field is unique
         if attrs.primary_key.value() {
             if primary_key_found {
@@ -112,7 +309,7 @@ fn get_index_config_implementation(
                 )
                 .to_compile_error();
             }
-            primary_key_attribute = field.ident.clone().unwrap().to_string();
+            primary_key_attribute = field_name.clone();
             primary_key_found = true;
         }
 
@@ -122,24 +319,28 @@ fn get_index_config_implementation(
                 return syn::Error::new(field.span(), "Only one field can be marked as distinct")
                     .to_compile_error();
             }
-            distinct_key_attribute = field.ident.clone().unwrap().to_string();
+            distinct_key_attribute = field_name.clone();
             distinct_found = true;
         }
 
         if attrs.displayed.value() {
-            displayed_attributes.push(field.ident.clone().unwrap().to_string());
+            displayed_attributes.push(field_name.clone());
         }
 
         if attrs.searchable.value() {
-            searchable_attributes.push(field.ident.clone().unwrap().to_string());
+            searchable_attributes.push(field_name.clone());
         }
 
         if attrs.filterable.value() {
-            filterable_attributes.push(field.ident.clone().unwrap().to_string());
+            filterable_attributes.push(field_name.clone());
         }
 
         if attrs.sortable.value() {
-            sortable_attributes.push(field.ident.clone().unwrap().to_string());
+            sortable_attributes.push(field_name.clone());
+        }
+
+        if attrs.embedded.value() {
+            embedded_fields.push(field_name.clone());
         }
     }
 
@@ -168,6 +369,39 @@ fn get_index_config_implementation(
 
     let pagination_token = get_pagination_token(&max_total_hits, "with_pagination");
 
+    let embedders_token = match get_embedders_token(&embedders, &embedded_fields) {
+        Ok(token) => token,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let ranking_rules_token = match get_ranking_rules_token(ranking_rules.as_ref(), &all_field_names)
+    {
+        Ok(token) => token,
+        Err(err) => return err.to_compile_error(),
+    };
+    let stop_words_token = stop_words
+        .as_ref()
+        .map(string_list_from_expr_array)
+        .map(|words| {
+            get_settings_token_for_list(
+                &words.into_iter().map(|(word, _)| word).collect::<Vec<_>>(),
+                "with_stop_words",
+            )
+        })
+        .unwrap_or_default();
+    let synonyms_token = get_synonyms_token(synonyms.as_ref());
+    let typo_tolerance_token = get_typo_tolerance_token(typo_tolerance.as_ref());
+
+    let search_cutoff_ms_token =
+        get_expr_setting_token(&search_cutoff_ms, "with_search_cutoff_ms");
+    let proximity_precision_token = match get_proximity_precision_token(proximity_precision.as_ref())
+    {
+        Ok(token) => token,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let faceting_token = get_faceting_token(faceting.as_ref(), &filterable_attributes);
+
     quote! {
         #[::meilisearch_sdk::macro_helper::async_trait(?Send)]
         impl ::meilisearch_sdk::documents::IndexConfig for #struct_ident {
@@ -181,14 +415,208 @@ fn get_index_config_implementation(
                 #searchable_attr_tokens
                 #distinct_attr_token
                 #pagination_token
+                #embedders_token
+                #ranking_rules_token
+                #stop_words_token
+                #synonyms_token
+                #typo_tolerance_token
+                #search_cutoff_ms_token
+                #proximity_precision_token
+                #faceting_token
             }
 
             async fn generate_index<Http: ::meilisearch_sdk::request::HttpClient>(client: &::meilisearch_sdk::client::Client<Http>) -> std::result::Result<::meilisearch_sdk::indexes::Index<Http>, ::meilisearch_sdk::tasks::Task> {
-                client.create_index(#index_name, #primary_key_token)
+                // `create_index`/`wait_for_completion` fail with `errors::Error`, which `Task`
+                // (this function's error type) has no `From` impl for, so those stay unwrapped;
+                // only `try_make_index`'s error is already a `Task` and can propagate via `?`.
+                let index = client.create_index(#index_name, #primary_key_token)
                     .await.unwrap()
                     .wait_for_completion(client, ::std::option::Option::None, ::std::option::Option::None)
                     .await.unwrap()
-                    .try_make_index(client)
+                    .try_make_index(client)?;
+
+                // Diffs the locally generated settings against whatever is already live on the
+                // index, so a `generate_index` call only pushes what actually changed instead of
+                // always re-sending the full settings object (and triggering a full reindex).
+                fn merge_scalar<T: ::std::cmp::PartialEq + ::std::clone::Clone>(
+                    live: &::std::option::Option<T>,
+                    desired: &::std::option::Option<T>,
+                ) -> ::std::option::Option<T> {
+                    let desired = desired.as_ref()?;
+                    if live.as_ref() == ::std::option::Option::Some(desired) {
+                        return ::std::option::Option::None;
+                    }
+                    ::std::option::Option::Some(desired.clone())
+                }
+
+                // If `desired` only adds to `live`, send the union so Meilisearch treats the
+                // change as additive; if anything was removed, fall back to a full replacement.
+                fn merge_additive_list(
+                    live: &::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                    desired: &::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                ) -> ::std::option::Option<::std::vec::Vec<::std::string::String>> {
+                    let desired = desired.as_ref()?;
+                    let live = match live {
+                        ::std::option::Option::None => return ::std::option::Option::Some(desired.clone()),
+                        ::std::option::Option::Some(live) => live,
+                    };
+
+                    if live == desired {
+                        return ::std::option::Option::None;
+                    }
+
+                    if live.iter().all(|attribute| desired.contains(attribute)) {
+                        let mut union = live.clone();
+                        for attribute in desired {
+                            if !union.contains(attribute) {
+                                union.push(attribute.clone());
+                            }
+                        }
+                        ::std::option::Option::Some(union)
+                    } else {
+                        ::std::option::Option::Some(desired.clone())
+                    }
+                }
+
+                // `generate_settings()` always emits `.with_x([])` for a list setting with no
+                // annotated fields, so an empty list here means "nothing was annotated", not
+                // "clear this setting" — treat it like the field was never set for the diff,
+                // without changing what `generate_settings()` itself reports to other callers.
+                fn non_empty_list(
+                    list: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+                ) -> ::std::option::Option<::std::vec::Vec<::std::string::String>> {
+                    list.filter(|list| !list.is_empty())
+                }
+
+                let desired = Self::generate_settings();
+                let live = index.get_settings().await.unwrap();
+
+                let desired_displayed_attributes = non_empty_list(desired.displayed_attributes.clone());
+                let desired_searchable_attributes = non_empty_list(desired.searchable_attributes.clone());
+                let desired_filterable_attributes = non_empty_list(desired.filterable_attributes.clone());
+                let desired_sortable_attributes = non_empty_list(desired.sortable_attributes.clone());
+
+                let mut diff = ::meilisearch_sdk::settings::Settings::new();
+                let mut has_diff = false;
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.displayed_attributes, &desired_displayed_attributes)
+                {
+                    diff = diff.with_displayed_attributes(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                // Searchable-attribute order drives attribute-ranking priority, so an additive
+                // union could silently keep the live order and discard the struct's intended
+                // priority; always send the desired order as a full replacement instead.
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.searchable_attributes, &desired_searchable_attributes)
+                {
+                    diff = diff.with_searchable_attributes(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_additive_list(&live.filterable_attributes, &desired_filterable_attributes)
+                {
+                    diff = diff.with_filterable_attributes(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.sortable_attributes, &desired_sortable_attributes)
+                {
+                    diff = diff.with_sortable_attributes(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.distinct_attribute, &desired.distinct_attribute)
+                {
+                    diff = diff.with_distinct_attribute(::std::option::Option::Some(value.as_str()));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.pagination, &desired.pagination)
+                {
+                    diff = diff.with_pagination(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.ranking_rules, &desired.ranking_rules)
+                {
+                    diff = diff.with_ranking_rules(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.stop_words, &desired.stop_words)
+                {
+                    diff = diff.with_stop_words(value.iter().map(::std::string::String::as_str));
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.synonyms, &desired.synonyms)
+                {
+                    diff = diff.with_synonyms(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.typo_tolerance, &desired.typo_tolerance)
+                {
+                    diff = diff.with_typo_tolerance(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.embedders, &desired.embedders)
+                {
+                    diff = diff.with_embedders(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.search_cutoff_ms, &desired.search_cutoff_ms)
+                {
+                    diff = diff.with_search_cutoff_ms(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.proximity_precision, &desired.proximity_precision)
+                {
+                    diff = diff.with_proximity_precision(value);
+                    has_diff = true;
+                }
+
+                if let ::std::option::Option::Some(value) =
+                    merge_scalar(&live.faceting, &desired.faceting)
+                {
+                    diff = diff.with_faceting(value);
+                    has_diff = true;
+                }
+
+                if has_diff {
+                    // Same reasoning as the index-creation task above: the network-level
+                    // `errors::Error` on these awaits can't become a `Task`, but the settings
+                    // task itself is already `Task`-typed, so surface *that* failure instead of
+                    // silently discarding it.
+                    let task = index
+                        .set_settings(&diff)
+                        .await.unwrap()
+                        .wait_for_completion(client, ::std::option::Option::None, ::std::option::Option::None)
+                        .await.unwrap();
+
+                    if !task.is_success() {
+                        return ::std::result::Result::Err(task);
+                    }
+                }
+
+                ::std::result::Result::Ok(index)
             }
         }
     }
@@ -208,6 +636,303 @@ fn get_pagination_token(
     }
 }
 
+fn get_expr_setting_token(
+    value: &Option<syn::Expr>,
+    method_name: &str,
+) -> proc_macro2::TokenStream {
+    let method_ident = Ident::new(method_name, proc_macro2::Span::call_site());
+
+    match value {
+        Some(value) => quote! { .#method_ident(#value) },
+        None => quote! {},
+    }
+}
+
+const VALID_PROXIMITY_PRECISIONS: &[&str] = &["byWord", "byAttribute"];
+
+fn get_proximity_precision_token(
+    proximity_precision: Option<&NameValue<LitStr>>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let Some(proximity_precision) = proximity_precision else {
+        return Ok(quote! {});
+    };
+
+    let precision = proximity_precision.value.value();
+    if !VALID_PROXIMITY_PRECISIONS.contains(&precision.as_str()) {
+        return Err(syn::Error::new(
+            proximity_precision.value.span(),
+            format!("proximity_precision must be `byWord` or `byAttribute`, found `{precision}`"),
+        ));
+    }
+
+    Ok(quote! { .with_proximity_precision(#precision.to_string()) })
+}
+
+// `meilisearch_sdk::settings::EmbedderSource` is a typed enum, not a free-form string, so
+// validate against its known variants here (same pattern as `get_proximity_precision_token`)
+// instead of stringly-typing `source` against the SDK's wire-format spelling.
+const VALID_EMBEDDER_SOURCES: &[&str] =
+    &["openAi", "huggingFace", "ollama", "userProvided", "rest", "composite"];
+
+fn get_embedder_source_token(source: &str, span: proc_macro2::Span) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let variant = match source {
+        "openAi" => quote! { OpenAi },
+        "huggingFace" => quote! { HuggingFace },
+        "ollama" => quote! { Ollama },
+        "userProvided" => quote! { UserProvided },
+        "rest" => quote! { Rest },
+        "composite" => quote! { Composite },
+        _ => {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "embedder source must be one of {}, found `{source}`",
+                    VALID_EMBEDDER_SOURCES.join(", ")
+                ),
+            ))
+        }
+    };
+
+    Ok(quote! { ::meilisearch_sdk::settings::EmbedderSource::#variant })
+}
+
+fn get_embedders_token(
+    embedders: &[EmbedderAttrs],
+    embedded_fields: &[String],
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if embedders.is_empty() {
+        return Ok(quote! {});
+    }
+
+    // When no explicit `document_template` is given, build one referencing every
+    // field marked `#[index_config(embedded)]` so the template matches the struct.
+    let default_template = (!embedded_fields.is_empty()).then(|| {
+        embedded_fields
+            .iter()
+            .map(|field| format!("{{{{doc.{field}}}}}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    let mut entries = Vec::with_capacity(embedders.len());
+    for embedder in embedders {
+        let name = embedder.name.value.value();
+        let source = embedder.source.value.value();
+        let source_token = get_embedder_source_token(&source, embedder.source.value.span())?;
+
+        let model_token = match embedder.model.as_ref().map(|model| model.value.value()) {
+            Some(model) => quote! { ::std::option::Option::Some(#model.to_string()) },
+            None => quote! { ::std::option::Option::None },
+        };
+
+        let template = embedder
+            .document_template
+            .as_ref()
+            .map(|template| template.value.value())
+            .or_else(|| default_template.clone());
+        let template_token = match template {
+            Some(template) => quote! { ::std::option::Option::Some(#template.to_string()) },
+            None => quote! { ::std::option::Option::None },
+        };
+
+        entries.push(quote! {
+            (#name.to_string(), ::meilisearch_sdk::settings::Embedder {
+                source: #source_token,
+                model: #model_token,
+                document_template: #template_token,
+                ..::std::default::Default::default()
+            })
+        });
+    }
+
+    Ok(quote! {
+        .with_embedders(::std::collections::HashMap::from([#(#entries),*]))
+    })
+}
+
+const BUILT_IN_RANKING_RULES: &[&str] =
+    &["words", "typo", "proximity", "attribute", "sort", "exactness"];
+
+// Custom ranking rules look like `"release_date:desc"`; validate that the attribute they
+// name is actually a field on the struct so a typo surfaces as a compile error.
+fn get_ranking_rules_token(
+    ranking_rules: Option<&syn::ExprArray>,
+    field_names: &[String],
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let Some(ranking_rules) = ranking_rules else {
+        return Ok(quote! {});
+    };
+
+    let rules = string_list_from_expr_array(ranking_rules);
+    for (rule, span) in &rules {
+        if BUILT_IN_RANKING_RULES.contains(&rule.as_str()) {
+            continue;
+        }
+
+        let Some((attribute, direction)) = rule.split_once(':') else {
+            return Err(syn::Error::new(
+                *span,
+                format!("`{rule}` is not a built-in ranking rule nor a `field:asc`/`field:desc` rule"),
+            ));
+        };
+
+        if direction != "asc" && direction != "desc" {
+            return Err(syn::Error::new(
+                *span,
+                format!("ranking rule direction must be `asc` or `desc`, found `{direction}`"),
+            ));
+        }
+
+        if !field_names.iter().any(|name| name == attribute) {
+            return Err(syn::Error::new(
+                *span,
+                format!("ranking rule references unknown field `{attribute}`"),
+            ));
+        }
+    }
+
+    let rule_values: Vec<String> = rules.into_iter().map(|(rule, _)| rule).collect();
+    Ok(get_settings_token_for_list(&rule_values, "with_ranking_rules"))
+}
+
+fn get_synonyms_token(synonyms: Option<&SynonymsAttrs>) -> proc_macro2::TokenStream {
+    let Some(synonyms) = synonyms else {
+        return quote! {};
+    };
+    if synonyms.rest.is_empty() {
+        return quote! {};
+    }
+
+    let entries = synonyms.rest.iter().map(|(word, with)| {
+        let with = string_list_from_expr_array(with)
+            .into_iter()
+            .map(|(value, _)| value);
+
+        quote! {
+            (#word.to_string(), ::std::vec![#(#with.to_string()),*])
+        }
+    });
+
+    quote! {
+        .with_synonyms(::std::collections::HashMap::from([#(#entries),*]))
+    }
+}
+
+fn get_typo_tolerance_token(
+    typo_tolerance: Option<&TypoToleranceAttrs>,
+) -> proc_macro2::TokenStream {
+    let Some(typo_tolerance) = typo_tolerance else {
+        return quote! {};
+    };
+
+    let min_word_size_token = match &typo_tolerance.min_word_size_for_typos {
+        Some(min_word_size) => {
+            let one_typo = match &min_word_size.args.one_typo {
+                Some(value) => {
+                    let value = &value.value;
+                    quote! { ::std::option::Option::Some(#value) }
+                }
+                None => quote! { ::std::option::Option::None },
+            };
+            let two_typos = match &min_word_size.args.two_typos {
+                Some(value) => {
+                    let value = &value.value;
+                    quote! { ::std::option::Option::Some(#value) }
+                }
+                None => quote! { ::std::option::Option::None },
+            };
+
+            quote! {
+                ::std::option::Option::Some(::meilisearch_sdk::settings::MinWordSizeForTypos {
+                    one_typo: #one_typo,
+                    two_typos: #two_typos,
+                })
+            }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let disable_on_words_token = match &typo_tolerance.disable_on_words {
+        Some(words) => {
+            let words = string_list_from_expr_array(&words.value)
+                .into_iter()
+                .map(|(value, _)| value);
+            quote! { ::std::option::Option::Some(::std::vec![#(#words.to_string()),*]) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let disable_on_attributes_token = match &typo_tolerance.disable_on_attributes {
+        Some(attributes) => {
+            let attributes = string_list_from_expr_array(&attributes.value)
+                .into_iter()
+                .map(|(value, _)| value);
+            quote! { ::std::option::Option::Some(::std::vec![#(#attributes.to_string()),*]) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    quote! {
+        .with_typo_tolerance(::meilisearch_sdk::settings::TypoToleranceSettings {
+            min_word_size_for_typos: #min_word_size_token,
+            disable_on_words: #disable_on_words_token,
+            disable_on_attributes: #disable_on_attributes_token,
+            ..::std::default::Default::default()
+        })
+    }
+}
+
+fn get_faceting_token(
+    faceting: Option<&FacetingAttrs>,
+    filterable_attributes: &[String],
+) -> proc_macro2::TokenStream {
+    let Some(faceting) = faceting else {
+        return quote! {};
+    };
+
+    let max_values_per_facet_token = match &faceting.max_values_per_facet {
+        Some(value) => {
+            let value = &value.value;
+            quote! { ::std::option::Option::Some(#value) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let entries: Vec<(String, String)> = match &faceting.sort_facet_values_by {
+        Some(sort_facet_values_by) => sort_facet_values_by
+            .args
+            .rest
+            .iter()
+            .map(|(field, order)| (field.clone(), order.value()))
+            .collect(),
+        // No explicit mapping: default every filterable attribute to alphabetical order so
+        // facet distribution UIs work out of the box.
+        None => filterable_attributes
+            .iter()
+            .map(|field| (field.clone(), "alpha".to_string()))
+            .collect(),
+    };
+
+    let sort_facet_values_by_token = if entries.is_empty() {
+        quote! { ::std::option::Option::None }
+    } else {
+        let entries = entries
+            .iter()
+            .map(|(field, order)| quote! { (#field.to_string(), #order.to_string()) });
+        quote! {
+            ::std::option::Option::Some(::std::collections::HashMap::from([#(#entries),*]))
+        }
+    };
+
+    quote! {
+        .with_faceting(::meilisearch_sdk::settings::FacetingSettings {
+            max_values_per_facet: #max_values_per_facet_token,
+            sort_facet_values_by: #sort_facet_values_by_token,
+            ..::std::default::Default::default()
+        })
+    }
+}
+
 fn get_settings_token_for_list(
     field_name_list: &[String],
     method_name: &str,
@@ -244,3 +969,92 @@ fn get_settings_token_for_string_for_some_string(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expr_array(src: &str) -> syn::ExprArray {
+        syn::parse_str(src).unwrap()
+    }
+
+    #[test]
+    fn rename_field_lowercase_and_uppercase_keep_underscores() {
+        assert_eq!(
+            rename_field("release_date", "lowercase").as_deref(),
+            Some("release_date")
+        );
+        assert_eq!(
+            rename_field("release_date", "UPPERCASE").as_deref(),
+            Some("RELEASE_DATE")
+        );
+    }
+
+    #[test]
+    fn rename_field_camel_case_splits_words() {
+        assert_eq!(
+            rename_field("release_date", "camelCase").as_deref(),
+            Some("releaseDate")
+        );
+    }
+
+    #[test]
+    fn rename_field_rejects_unknown_casing() {
+        assert_eq!(rename_field("release_date", "made_up_case"), None);
+    }
+
+    #[test]
+    fn resolve_field_name_prefers_explicit_over_rename_all() {
+        assert_eq!(
+            resolve_field_name("release_date", &[], Some("custom"), Some("camelCase")),
+            "custom"
+        );
+        assert_eq!(
+            resolve_field_name("release_date", &[], None, Some("camelCase")),
+            "releaseDate"
+        );
+        assert_eq!(resolve_field_name("release_date", &[], None, None), "release_date");
+    }
+
+    #[test]
+    fn find_serde_rename_reads_nested_meta() {
+        let attr: Attribute = syn::parse_quote!(#[serde(rename = "desc")]);
+        assert_eq!(find_serde_rename(&[attr], "rename").as_deref(), Some("desc"));
+    }
+
+    #[test]
+    fn ranking_rules_accepts_built_in_and_known_field_rules() {
+        let array = expr_array(r#"["words", "typo", "release_date:desc"]"#);
+        let fields = vec!["release_date".to_string()];
+        assert!(get_ranking_rules_token(Some(&array), &fields).is_ok());
+    }
+
+    #[test]
+    fn ranking_rules_rejects_unknown_field() {
+        let array = expr_array(r#"["title:desc"]"#);
+        let fields = vec!["release_date".to_string()];
+        let err = get_ranking_rules_token(Some(&array), &fields).unwrap_err();
+        assert!(err.to_string().contains("unknown field"));
+    }
+
+    #[test]
+    fn ranking_rules_rejects_bad_direction() {
+        let array = expr_array(r#"["release_date:sideways"]"#);
+        let fields = vec!["release_date".to_string()];
+        let err = get_ranking_rules_token(Some(&array), &fields).unwrap_err();
+        assert!(err.to_string().contains("asc"));
+    }
+
+    #[test]
+    fn embedder_source_accepts_known_variants() {
+        for source in VALID_EMBEDDER_SOURCES {
+            assert!(get_embedder_source_token(source, proc_macro2::Span::call_site()).is_ok());
+        }
+    }
+
+    #[test]
+    fn embedder_source_rejects_unknown_variant() {
+        let err = get_embedder_source_token("azureOpenAi", proc_macro2::Span::call_site()).unwrap_err();
+        assert!(err.to_string().contains("embedder source"));
+    }
+}